@@ -0,0 +1,87 @@
+//! Support for Flash's `ExternalInterface`, which lets host code (the JS/native
+//! embedder) call into ActionScript by name and vice versa.
+
+/// A value passed across the `ExternalInterface` boundary.
+///
+/// AVM1 `Value`s are tied to the GC arena and can't be held onto by the backend,
+/// so calls are marshalled through this plain, backend-agnostic representation
+/// instead (e.g. to/from JSON when the backend is a web embedder).
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExternalValue {
+    Null,
+    Bool(bool),
+    Number(f64),
+    String(String),
+    List(Vec<ExternalValue>),
+    Object(Vec<(String, ExternalValue)>),
+}
+
+/// An inbound call from the host into ActionScript, awaiting a response.
+pub struct ExternalCall {
+    /// The name of the ActionScript method to invoke, e.g. `"myMovie.myFunction"`.
+    pub name: String,
+
+    /// The arguments to pass to the method.
+    pub args: Vec<ExternalValue>,
+}
+
+/// The backend used to bridge `ExternalInterface` calls between the host and ActionScript.
+///
+/// The embedder implements this to let its host language invoke ActionScript functions
+/// registered via `ExternalInterface.addCallback`, and to receive the result of ActionScript
+/// calling out via `ExternalInterface.call`.
+pub trait ExternalInterfaceBackend {
+    /// Queues an inbound call from the host to be run against ActionScript.
+    /// The result of the call, once executed, is delivered via `response`.
+    fn call_method(&mut self, call: ExternalCall, response: ExternalInterfaceResponder);
+
+    /// Registers a callback name that ActionScript has made available via
+    /// `ExternalInterface.addCallback`, so the host knows it can be called.
+    fn register_callback(&mut self, name: String);
+
+    /// Delivers an outbound call to the host, initiated by ActionScript calling
+    /// `ExternalInterface.call`, returning the host's response.
+    fn call_external(&mut self, name: String, args: Vec<ExternalValue>) -> ExternalValue;
+}
+
+/// A handle used to deliver the result of a queued `ExternalCall` back to the host
+/// once the corresponding `ActionType::ExternalCall` has run.
+pub struct ExternalInterfaceResponder {
+    callback: Box<dyn FnOnce(ExternalValue)>,
+}
+
+impl ExternalInterfaceResponder {
+    pub fn new(callback: impl FnOnce(ExternalValue) + 'static) -> Self {
+        Self {
+            callback: Box::new(callback),
+        }
+    }
+
+    /// Hands the result of the call back to the host.
+    pub fn respond(self, result: ExternalValue) {
+        (self.callback)(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn respond_delivers_result_to_callback() {
+        let observed = Rc::new(RefCell::new(None));
+        let observed_clone = observed.clone();
+
+        let responder = ExternalInterfaceResponder::new(move |result| {
+            *observed_clone.borrow_mut() = Some(result);
+        });
+        responder.respond(ExternalValue::String("hello".to_string()));
+
+        assert_eq!(
+            *observed.borrow(),
+            Some(ExternalValue::String("hello".to_string()))
+        );
+    }
+}
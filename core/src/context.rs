@@ -3,7 +3,12 @@ use crate::avm1;
 
 use crate::avm1::listeners::SystemListener;
 use crate::avm1::Value;
-use crate::backend::{audio::AudioBackend, navigator::NavigatorBackend, render::RenderBackend};
+use crate::backend::{
+    audio::AudioBackend,
+    external_interface::{ExternalInterfaceBackend, ExternalInterfaceResponder},
+    navigator::NavigatorBackend,
+    render::RenderBackend,
+};
 use crate::library::Library;
 use crate::prelude::*;
 use crate::tag_utils::SwfSlice;
@@ -58,6 +63,10 @@ pub struct UpdateContext<'a, 'gc, 'gc_context> {
     /// The renderer, used by the display objects to draw themselves.
     pub renderer: &'a mut dyn RenderBackend,
 
+    /// The external interface, used by `ExternalInterface` to communicate between
+    /// ActionScript and the host (JS/native embedder).
+    pub external_interface: &'a mut dyn ExternalInterfaceBackend,
+
     /// The RNG, used by the AVM `RandomNumber` opcode,  `Math.random(),` and `random()`.
     pub rng: &'a mut SmallRng,
 
@@ -115,9 +124,33 @@ unsafe impl<'gc> Collect for QueuedActions<'gc> {
     }
 }
 
+/// The relative ordering in which a `QueuedActions` should run within a frame.
+///
+/// Flash executes queued work in distinct priority bands: `DoInitAction`/constructor
+/// code runs first, then normal frame actions, then event-method callbacks like
+/// `onEnterFrame`. `ActionQueue` keeps one sub-queue per priority so that actions
+/// queued out of order (e.g. an `Init` queued after a `Normal`) still run in the
+/// order Flash expects.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum ActionPriority {
+    /// `DoInitAction` and other clip-initialization code. Runs first.
+    Init = 0,
+
+    /// Normal frame actions, e.g. frame scripts. Runs after `Init`.
+    Normal = 1,
+
+    /// Event handler methods and listener notifications, e.g. `onEnterFrame`. Runs last.
+    Method = 2,
+}
+
+impl ActionPriority {
+    /// The number of priority levels, used to size `ActionQueue`'s sub-queues.
+    const NUM_LEVELS: usize = 3;
+}
+
 /// Action and gotos need to be queued up to execute at the end of the frame.
 pub struct ActionQueue<'gc> {
-    queue: std::collections::VecDeque<QueuedActions<'gc>>,
+    action_queue: [std::collections::VecDeque<QueuedActions<'gc>>; ActionPriority::NUM_LEVELS],
 }
 
 impl<'gc> ActionQueue<'gc> {
@@ -126,7 +159,11 @@ impl<'gc> ActionQueue<'gc> {
     /// Crates a new `ActionQueue` with an empty queue.
     pub fn new() -> Self {
         Self {
-            queue: std::collections::VecDeque::with_capacity(Self::DEFAULT_CAPACITY),
+            action_queue: [
+                std::collections::VecDeque::with_capacity(Self::DEFAULT_CAPACITY),
+                std::collections::VecDeque::with_capacity(Self::DEFAULT_CAPACITY),
+                std::collections::VecDeque::with_capacity(Self::DEFAULT_CAPACITY),
+            ],
         }
     }
 
@@ -139,7 +176,8 @@ impl<'gc> ActionQueue<'gc> {
         action_type: ActionType<'gc>,
         is_unload: bool,
     ) {
-        self.queue.push_back(QueuedActions {
+        let priority = action_type.priority();
+        self.action_queue[priority as usize].push_back(QueuedActions {
             clip,
             action_type,
             is_unload,
@@ -147,8 +185,15 @@ impl<'gc> ActionQueue<'gc> {
     }
 
     /// Pops the next actions off of the queue.
+    /// Higher priority levels are drained before lower ones, and insertion order
+    /// is preserved within a level.
     pub fn pop(&mut self) -> Option<QueuedActions<'gc>> {
-        self.queue.pop_front()
+        for queue in &mut self.action_queue {
+            if let Some(actions) = queue.pop_front() {
+                return Some(actions);
+            }
+        }
+        None
     }
 }
 
@@ -161,7 +206,9 @@ impl<'gc> Default for ActionQueue<'gc> {
 unsafe impl<'gc> Collect for ActionQueue<'gc> {
     #[inline]
     fn trace(&self, cc: gc_arena::CollectionContext) {
-        self.queue.iter().for_each(|o| o.trace(cc));
+        for queue in &self.action_queue {
+            queue.iter().for_each(|o| o.trace(cc));
+        }
     }
 }
 
@@ -184,7 +231,6 @@ pub struct RenderContext<'a, 'gc> {
 }
 
 /// The type of action being run.
-#[derive(Clone)]
 pub enum ActionType<'gc> {
     /// Normal frame or event actions.
     Normal { bytecode: SwfSlice },
@@ -201,6 +247,28 @@ pub enum ActionType<'gc> {
         method: &'static str,
         args: Vec<Value<'gc>>,
     },
+
+    /// An inbound call from the host to a method registered via `ExternalInterface.addCallback`,
+    /// resolving the named method on the target clip and delivering the result back to the host.
+    ExternalCall {
+        name: String,
+        args: Vec<Value<'gc>>,
+        response: ExternalInterfaceResponder,
+    },
+}
+
+impl<'gc> ActionType<'gc> {
+    /// The priority level this action runs at within a frame.
+    /// See `ActionPriority` for the ordering Flash expects.
+    pub fn priority(&self) -> ActionPriority {
+        match self {
+            ActionType::Init { .. } => ActionPriority::Init,
+            ActionType::Normal { .. } => ActionPriority::Normal,
+            ActionType::Method { .. }
+            | ActionType::NotifyListeners { .. }
+            | ActionType::ExternalCall { .. } => ActionPriority::Method,
+        }
+    }
 }
 
 impl fmt::Debug for ActionType<'_> {
@@ -228,6 +296,11 @@ impl fmt::Debug for ActionType<'_> {
                 .field("method", method)
                 .field("args", args)
                 .finish(),
+            ActionType::ExternalCall { name, args, .. } => f
+                .debug_struct("ActionType::ExternalCall")
+                .field("name", name)
+                .field("args", args)
+                .finish(),
         }
     }
 }
@@ -235,8 +308,93 @@ impl fmt::Debug for ActionType<'_> {
 unsafe impl<'gc> Collect for ActionType<'gc> {
     #[inline]
     fn trace(&self, cc: gc_arena::CollectionContext) {
-        if let ActionType::NotifyListeners { args, .. } = self {
-            args.trace(cc);
+        match self {
+            ActionType::NotifyListeners { args, .. } => args.trace(cc),
+            ActionType::ExternalCall { args, .. } => args.trace(cc),
+            _ => {}
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::avm1::test_utils::with_avm;
+    use std::sync::Arc;
+
+    fn test_bytecode() -> SwfSlice {
+        SwfSlice::empty(Arc::new(crate::tag_utils::SwfMovie::empty(6)))
+    }
+
+    #[test]
+    fn pop_drains_tiers_in_flash_order_preserving_insertion_order() {
+        with_avm(6, |_avm, _context, root| {
+            let mut queue = ActionQueue::new();
+
+            // Queue out of priority order: Method, Normal, Init, Normal, Init.
+            queue.queue_actions(
+                root,
+                ActionType::Method {
+                    name: "onEnterFrame",
+                },
+                false,
+            );
+            queue.queue_actions(
+                root,
+                ActionType::Normal {
+                    bytecode: test_bytecode(),
+                },
+                false,
+            );
+            queue.queue_actions(
+                root,
+                ActionType::Init {
+                    bytecode: test_bytecode(),
+                },
+                false,
+            );
+            queue.queue_actions(
+                root,
+                ActionType::Normal {
+                    bytecode: test_bytecode(),
+                },
+                false,
+            );
+            queue.queue_actions(
+                root,
+                ActionType::Init {
+                    bytecode: test_bytecode(),
+                },
+                false,
+            );
+
+            // Both `Init`s should drain first (in insertion order), then both `Normal`s,
+            // then the `Method`, matching Flash's init -> frame -> event execution order.
+            let order: Vec<ActionPriority> = std::iter::from_fn(|| queue.pop())
+                .map(|actions| actions.action_type.priority())
+                .collect();
+
+            assert_eq!(
+                order,
+                vec![
+                    ActionPriority::Init,
+                    ActionPriority::Init,
+                    ActionPriority::Normal,
+                    ActionPriority::Normal,
+                    ActionPriority::Method,
+                ]
+            );
+        });
+    }
+
+    #[test]
+    fn external_call_runs_at_method_priority() {
+        let action_type: ActionType<'_> = ActionType::ExternalCall {
+            name: "myMovie.myFunction".to_string(),
+            args: vec![],
+            response: ExternalInterfaceResponder::new(|_| {}),
+        };
+
+        assert_eq!(action_type.priority(), ActionPriority::Method);
+    }
+}